@@ -1,8 +1,31 @@
 use super::data;
 use base64::prelude::*;
-use image::{DynamicImage, ImageFormat};
+use image::codecs::gif::{GifDecoder, GifEncoder, Repeat};
+use image::codecs::png::PngDecoder;
+use image::codecs::webp::WebPDecoder;
+use image::{AnimationDecoder, Delay, DynamicImage, Frame, ImageFormat, Rgba, RgbaImage};
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::io::Cursor;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use wgpu::util::DeviceExt;
 extern crate lazy_static;
 
+// The cadence baked-in and directory-loaded frames are assumed to play
+// at when the source has no per-frame timing of its own.
+const DEFAULT_FRAME_DELAY: Duration = Duration::from_millis(100);
+
+// `numer/denom` (from `image::Delay::numer_denom_ms`) can be a fractional
+// number of milliseconds; keep that precision instead of truncating to
+// whole ms.
+fn duration_from_numer_denom_ms(numer: u32, denom: u32) -> Duration {
+    let delay_ms = numer as f64 / denom.max(1) as f64;
+    Duration::from_secs_f64(delay_ms / 1000.0)
+}
+
 pub fn precompute() {
     _ = SPLATTER_0;
     _ = SPLATTER_1;
@@ -23,9 +46,115 @@ impl SplatterImages {
     }
 }
 
+// A variable-length list of decoded animation frames for one splatter size.
+// Unlike the four fixed slots the baked-in assets use, a set loaded via
+// `Splatter::from_dir` can hold any number of frames.
+#[derive(Clone)]
+pub struct SplatterSet {
+    // `Arc`-wrapped so `frame()` can hand out a cheap reference-counted
+    // clone instead of deep-copying pixel data on every draw.
+    pub frames: Vec<Arc<DynamicImage>>,
+    pub delays: Vec<Duration>,
+}
+
+impl SplatterSet {
+    fn from_baked(frames: [&'static DynamicImage; 4]) -> SplatterSet {
+        let frames: Vec<Arc<DynamicImage>> = frames.iter().map(|img| Arc::new((*img).clone())).collect();
+        let delays = vec![DEFAULT_FRAME_DELAY; frames.len()];
+        SplatterSet { frames, delays }
+    }
+    // Scans `dir` for PNG frames, sorts them by filename, and decodes each
+    // one via the `image` crate.
+    fn from_dir(dir: &Path) -> io::Result<SplatterSet> {
+        let mut paths: Vec<_> = fs::read_dir(dir)?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().map(|ext| ext == "png").unwrap_or(false))
+            .collect();
+        paths.sort();
+
+        let frames = paths
+            .iter()
+            .map(|path| {
+                image::open(path)
+                    .map(Arc::new)
+                    .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+            })
+            .collect::<io::Result<Vec<Arc<DynamicImage>>>>()?;
+        if frames.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("no .png frames found in {}", dir.display()),
+            ));
+        }
+        let delays = vec![DEFAULT_FRAME_DELAY; frames.len()];
+        Ok(SplatterSet { frames, delays })
+    }
+    // Decodes every frame (and its own delay) out of an APNG or animated
+    // WebP in one shot, rather than requiring one still image per frame.
+    // Returns `io::Result` to match the error contract `from_dir` uses,
+    // rather than panicking on a malformed or unsupported source.
+    fn from_animation_bytes(bytes: &[u8]) -> io::Result<SplatterSet> {
+        let format = image::guess_format(bytes)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        let decoded = match format {
+            ImageFormat::Png => PngDecoder::new(Cursor::new(bytes))
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?
+                .apng()
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?
+                .into_frames(),
+            ImageFormat::WebP => WebPDecoder::new(Cursor::new(bytes))
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?
+                .into_frames(),
+            other => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("unsupported splatter animation format: {other:?}"),
+                ))
+            }
+        };
+
+        let mut frames = Vec::new();
+        let mut delays = Vec::new();
+        for frame in decoded {
+            let frame = frame.map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+            let (numer, denom) = frame.delay().numer_denom_ms();
+            delays.push(duration_from_numer_denom_ms(numer, denom));
+            frames.push(Arc::new(DynamicImage::ImageRgba8(frame.into_buffer())));
+        }
+        if frames.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "no frames found in splatter animation",
+            ));
+        }
+        Ok(SplatterSet { frames, delays })
+    }
+    // Mirrors `Splatter::frame`'s clamp-to-last-frame behavior, so variable
+    // frame counts work with no caller change. Returns a cheap `Arc` clone
+    // rather than deep-copying the frame's pixel data.
+    fn frame(&self, frame: usize) -> Arc<DynamicImage> {
+        if let Some(img) = self.frames.get(frame) {
+            img.clone()
+        } else {
+            self.frames.last().expect("SplatterSet has no frames").clone()
+        }
+    }
+    // Respects the asset's own per-frame timing instead of assuming a fixed
+    // 4-step cadence; clamps the same way `frame()` does.
+    fn frame_delay(&self, frame: usize) -> Duration {
+        self.delays
+            .get(frame)
+            .copied()
+            .or_else(|| self.delays.last().copied())
+            .unwrap_or(DEFAULT_FRAME_DELAY)
+    }
+}
+
+#[derive(Clone)]
 pub struct Splatter {
-    pub frames_r: [&'static DynamicImage; 4],
-    pub frames_l: [&'static DynamicImage; 4],
+    pub regular: SplatterSet,
+    pub large: SplatterSet,
 }
 
 pub enum SplatterSize {
@@ -57,10 +186,30 @@ fn get_frames(splatter_num: u8, size: SplatterSize) -> [&'static DynamicImage; 4
 impl Splatter {
     pub fn num(num: u8) -> Splatter {
         Splatter {
-            frames_r: get_frames(num, SplatterSize::Regular),
-            frames_l: get_frames(num, SplatterSize::Large),
+            regular: SplatterSet::from_baked(get_frames(num, SplatterSize::Regular)),
+            large: SplatterSet::from_baked(get_frames(num, SplatterSize::Large)),
         }
     }
+    // Loads a splatter from `dir/regular` and `dir/large` subfolders instead
+    // of compiled-in base64 assets, so new splatter styles are a directory
+    // drop-in rather than a recompile.
+    pub fn from_dir<P: AsRef<Path>>(dir: P) -> io::Result<Splatter> {
+        let dir = dir.as_ref();
+        Ok(Splatter {
+            regular: SplatterSet::from_dir(&dir.join("regular"))?,
+            large: SplatterSet::from_dir(&dir.join("large"))?,
+        })
+    }
+    // Builds a splatter from a single animated container (APNG or animated
+    // WebP) instead of four hand-split base64 blobs; the decoded set is used
+    // for both sizes since the source animation carries no size split.
+    pub fn from_animation_bytes(bytes: &[u8]) -> io::Result<Splatter> {
+        let set = SplatterSet::from_animation_bytes(bytes)?;
+        Ok(Splatter {
+            regular: set.clone(),
+            large: set,
+        })
+    }
     pub fn at(&self, x: f32, y: f32, size: &SplatterSize) -> (i64, i64) {
         let half = match size {
             SplatterSize::Regular => 120.0,
@@ -68,19 +217,112 @@ impl Splatter {
         };
         ((x - half).floor() as i64, (y - half).floor() as i64)
     }
-    pub fn frame(&self, frame: usize, size: &SplatterSize) -> &DynamicImage {
-        let frames = match size {
-            SplatterSize::Regular => &self.frames_r,
-            SplatterSize::Large => &self.frames_l,
+    // Returns a cheap `Arc` clone of the frame rather than deep-copying its
+    // pixel data on every draw.
+    pub fn frame(&self, frame: usize, size: &SplatterSize) -> Arc<DynamicImage> {
+        let set = match size {
+            SplatterSize::Regular => &self.regular,
+            SplatterSize::Large => &self.large,
         };
-        if let Some(img) = frames.get(frame) {
-            img
-        } else {
-            frames.last().unwrap()
+        set.frame(frame)
+    }
+    // Respects the asset's own per-frame timing instead of assuming a fixed
+    // 4-step cadence; clamps the same way `frame()` does. Every constructor
+    // gives `regular` and `large` the same delays (`DEFAULT_FRAME_DELAY` for
+    // baked-in and directory-loaded sets, one shared decoded animation for
+    // `from_animation_bytes`), so there's no `size` parameter; the delays of
+    // `regular` are the ones read.
+    pub fn frame_delay(&self, frame: usize) -> Duration {
+        self.regular.frame_delay(frame)
+    }
+    // Recolors `frame` to the requested hue/saturation while keeping the
+    // source's luminance and alpha mask, so any paint color can be drawn at
+    // runtime instead of only the four baked-in splatter variants. `index`
+    // is the registry index `self` is known by (the same one passed to
+    // `for_index`); results are cached per `(index, frame, size, tint)`
+    // rather than by address, since addresses get reused once a `Splatter`
+    // is dropped or replaced via `register()`.
+    pub fn frame_tinted(&self, index: usize, frame: usize, size: &SplatterSize, tint: Rgba<u8>) -> DynamicImage {
+        let source = self.frame(frame, size);
+        let key = (index, frame, size_tag(size), tint.0);
+        if let Some(cached) = TINT_CACHE.lock().unwrap().get(&key) {
+            return cached.clone();
         }
+        let tinted = tint_image(&source, tint);
+        TINT_CACHE.lock().unwrap().insert(key, tinted.clone());
+        tinted
+    }
+}
+
+fn size_tag(size: &SplatterSize) -> u8 {
+    match size {
+        SplatterSize::Regular => 0,
+        SplatterSize::Large => 1,
     }
 }
 
+lazy_static! {
+    static ref TINT_CACHE: Mutex<HashMap<(usize, usize, u8, [u8; 4]), DynamicImage>> =
+        Mutex::new(HashMap::new());
+}
+
+fn tint_image(source: &DynamicImage, tint: Rgba<u8>) -> DynamicImage {
+    let rgba = source.to_rgba8();
+    let (target_h, target_s, _) = rgb_to_hsv(tint.0[0], tint.0[1], tint.0[2]);
+    let mut out = RgbaImage::new(rgba.width(), rgba.height());
+    for (x, y, px) in rgba.enumerate_pixels() {
+        let (_, _, v) = rgb_to_hsv(px[0], px[1], px[2]);
+        let (r, g, b) = hsv_to_rgb(target_h, target_s, v);
+        let alpha = ((px[3] as u16 * tint.0[3] as u16) / 255) as u8;
+        out.put_pixel(x, y, Rgba([r, g, b, alpha]));
+    }
+    DynamicImage::ImageRgba8(out)
+}
+
+fn rgb_to_hsv(r: u8, g: u8, b: u8) -> (f32, f32, f32) {
+    let rf = r as f32 / 255.0;
+    let gf = g as f32 / 255.0;
+    let bf = b as f32 / 255.0;
+    let max = rf.max(gf).max(bf);
+    let min = rf.min(gf).min(bf);
+    let delta = max - min;
+    let h = if delta == 0.0 {
+        0.0
+    } else if max == rf {
+        60.0 * (((gf - bf) / delta).rem_euclid(6.0))
+    } else if max == gf {
+        60.0 * (((bf - rf) / delta) + 2.0)
+    } else {
+        60.0 * (((rf - gf) / delta) + 4.0)
+    };
+    let s = if max == 0.0 { 0.0 } else { delta / max };
+    (h, s, max)
+}
+
+fn hsv_to_rgb(h: f32, s: f32, v: f32) -> (u8, u8, u8) {
+    let c = v * s;
+    let x = c * (1.0 - (((h / 60.0) % 2.0) - 1.0).abs());
+    let m = v - c;
+    let (rf, gf, bf) = if h < 60.0 {
+        (c, x, 0.0)
+    } else if h < 120.0 {
+        (x, c, 0.0)
+    } else if h < 180.0 {
+        (0.0, c, x)
+    } else if h < 240.0 {
+        (0.0, x, c)
+    } else if h < 300.0 {
+        (x, 0.0, c)
+    } else {
+        (c, 0.0, x)
+    };
+    (
+        ((rf + m) * 255.0).round() as u8,
+        ((gf + m) * 255.0).round() as u8,
+        ((bf + m) * 255.0).round() as u8,
+    )
+}
+
 fn image_from_str(string: &str) -> DynamicImage {
     let data = BASE64_STANDARD_NO_PAD
         .decode(string)
@@ -88,19 +330,169 @@ fn image_from_str(string: &str) -> DynamicImage {
     image::load_from_memory_with_format(&data, ImageFormat::Png).unwrap()
 }
 
+// Registry of loaded splatters, keyed by the same `index` callers pass to
+// `for_index`. Seeded with the four baked-in sets; `register` lets callers
+// add directory-loaded sets (e.g. from `Splatter::from_dir`) at runtime.
+lazy_static! {
+    static ref REGISTRY: Mutex<HashMap<usize, Splatter>> = Mutex::new(default_registry());
+}
+
+fn default_registry() -> HashMap<usize, Splatter> {
+    let mut registry = HashMap::new();
+    registry.insert(0, SPLATTER_0.clone());
+    registry.insert(1, SPLATTER_1.clone());
+    registry.insert(2, SPLATTER_2.clone());
+    registry.insert(3, SPLATTER_3.clone());
+    registry
+}
+
+pub fn register(index: usize, splatter: Splatter) {
+    // Drop tints cached for whatever splatter `index` held before.
+    TINT_CACHE.lock().unwrap().retain(|key, _| key.0 != index);
+    REGISTRY.lock().unwrap().insert(index, splatter);
+}
+
+// Returns a cheap `Arc` clone of the selected frame rather than deep-copying
+// its pixel data on every draw.
 pub fn for_index(
     index: usize,
     frame: usize,
     size: SplatterSize,
     x: f32,
     y: f32,
-) -> (&'static DynamicImage, (i64, i64)) {
-    match index {
-        1 => (SPLATTER_1.frame(frame, &size), SPLATTER_1.at(x, y, &size)),
-        2 => (SPLATTER_2.frame(frame, &size), SPLATTER_2.at(x, y, &size)),
-        3 => (SPLATTER_3.frame(frame, &size), SPLATTER_3.at(x, y, &size)),
-        _ => (SPLATTER_0.frame(frame, &size), SPLATTER_0.at(x, y, &size)),
+) -> (Arc<DynamicImage>, (i64, i64)) {
+    let registry = REGISTRY.lock().unwrap();
+    let splatter = registry
+        .get(&index)
+        .unwrap_or_else(|| registry.get(&0).expect("default splatter registered"));
+    (splatter.frame(frame, &size), splatter.at(x, y, &size))
+}
+
+// Renders one full splatter cycle to an animated GIF for previewing and
+// sharing effects, without standing up the whole draw surface. Reuses
+// `frame()` for frame selection; each frame is centered on the canvas by its
+// own dimensions rather than `at()`'s fixed 120/200 regular/large halves, so
+// directory- or animation-loaded frames of any size land correctly instead
+// of being clipped.
+pub fn encode_animation(index: usize, size: SplatterSize, fps: u16) -> Vec<u8> {
+    let registry = REGISTRY.lock().unwrap();
+    let splatter = registry
+        .get(&index)
+        .unwrap_or_else(|| registry.get(&0).expect("default splatter registered"));
+    let set = match size {
+        SplatterSize::Regular => &splatter.regular,
+        SplatterSize::Large => &splatter.large,
+    };
+
+    let canvas_w = set.frames.iter().map(|img| img.width()).max().unwrap_or(0);
+    let canvas_h = set.frames.iter().map(|img| img.height()).max().unwrap_or(0);
+    let delay = Delay::from_numer_denom_ms(1000, u32::from(fps.max(1)));
+
+    let mut bytes = Vec::new();
+    {
+        let mut encoder = GifEncoder::new(&mut bytes);
+        encoder
+            .set_repeat(Repeat::Infinite)
+            .expect("failed to set splatter gif repeat mode");
+        for i in 0..set.frames.len() {
+            let frame = set.frame(i);
+            let left = ((canvas_w - frame.width()) / 2) as i64;
+            let top = ((canvas_h - frame.height()) / 2) as i64;
+            let mut canvas = RgbaImage::new(canvas_w, canvas_h);
+            image::imageops::overlay(&mut canvas, frame.as_ref(), left, top);
+            encoder
+                .encode_frame(Frame::from_parts(canvas, 0, 0, delay))
+                .expect("failed to encode splatter gif frame");
+        }
+    }
+    bytes
+}
+
+// Like `for_index`, but applies a rotation (about the splatter's center) and
+// uniform scale to the selected frame before returning it, so repeated
+// splats from the same four baked assets don't all look identical. The
+// returned placement centers the rotated/scaled bounds on (x, y), replacing
+// the fixed 120/200 `half` offsets `at()` uses for the untransformed frame.
+pub fn for_index_transformed(
+    index: usize,
+    frame: usize,
+    size: SplatterSize,
+    x: f32,
+    y: f32,
+    angle_rad: f32,
+    scale: f32,
+) -> (DynamicImage, (i64, i64)) {
+    let (source, _) = for_index(index, frame, size, x, y);
+    let transformed = transform_image(source.as_ref(), angle_rad, scale);
+    let half_w = transformed.width() as f32 / 2.0;
+    let half_h = transformed.height() as f32 / 2.0;
+    let pos = ((x - half_w).floor() as i64, (y - half_h).floor() as i64);
+    (transformed, pos)
+}
+
+fn transform_image(source: &DynamicImage, angle_rad: f32, scale: f32) -> DynamicImage {
+    let rgba = source.to_rgba8();
+    let (sw, sh) = (rgba.width() as f32, rgba.height() as f32);
+    let (sin_a, cos_a) = angle_rad.sin_cos();
+    // Trim float noise (e.g. cos(pi/2) comes out near -4e-8, not 0) so a
+    // bound that is an integer doesn't ceil up to one extra pixel.
+    let out_w = ((sw * cos_a.abs() + sh * sin_a.abs()) * scale - 1e-3).ceil().max(1.0) as u32;
+    let out_h = ((sw * sin_a.abs() + sh * cos_a.abs()) * scale - 1e-3).ceil().max(1.0) as u32;
+    let (scx, scy) = (sw / 2.0, sh / 2.0);
+    let (dcx, dcy) = (out_w as f32 / 2.0, out_h as f32 / 2.0);
+    let inv_scale = 1.0 / scale;
+
+    let mut out = RgbaImage::new(out_w, out_h);
+    for dy in 0..out_h {
+        for dx in 0..out_w {
+            let rx = dx as f32 + 0.5 - dcx;
+            let ry = dy as f32 + 0.5 - dcy;
+            // Inverse-map the destination pixel through the rotation+scale
+            // back into source space, then bilinearly sample it.
+            let sx = (rx * cos_a + ry * sin_a) * inv_scale + scx;
+            let sy = (-rx * sin_a + ry * cos_a) * inv_scale + scy;
+            out.put_pixel(dx, dy, sample_bilinear(&rgba, sx, sy));
+        }
     }
+    DynamicImage::ImageRgba8(out)
+}
+
+// Bilinearly samples `img` at `(x, y)`, treating out-of-bounds coordinates
+// as fully transparent.
+fn sample_bilinear(img: &RgbaImage, x: f32, y: f32) -> Rgba<u8> {
+    let (w, h) = (img.width() as i64, img.height() as i64);
+    let x0f = x.floor();
+    let y0f = y.floor();
+    let fx = x - x0f;
+    let fy = y - y0f;
+    let x0 = x0f as i64;
+    let y0 = y0f as i64;
+
+    let get = |ix: i64, iy: i64| -> [f32; 4] {
+        if ix < 0 || iy < 0 || ix >= w || iy >= h {
+            [0.0, 0.0, 0.0, 0.0]
+        } else {
+            let px = img.get_pixel(ix as u32, iy as u32);
+            [px[0] as f32, px[1] as f32, px[2] as f32, px[3] as f32]
+        }
+    };
+    let c00 = get(x0, y0);
+    let c10 = get(x0 + 1, y0);
+    let c01 = get(x0, y0 + 1);
+    let c11 = get(x0 + 1, y0 + 1);
+
+    let mut out = [0.0f32; 4];
+    for i in 0..4 {
+        let top = c00[i] * (1.0 - fx) + c10[i] * fx;
+        let bottom = c01[i] * (1.0 - fx) + c11[i] * fx;
+        out[i] = top * (1.0 - fy) + bottom * fy;
+    }
+    Rgba([
+        out[0].round() as u8,
+        out[1].round() as u8,
+        out[2].round() as u8,
+        out[3].round() as u8,
+    ])
 }
 
 lazy_static! {
@@ -157,3 +549,624 @@ lazy_static! {
         i_3: image_from_str(&data::SPLATTER_3_DATA_LARGE[3])
     };
 }
+
+// One splatter draw command for `GpuSplatterRenderer::render_batch`.
+pub struct SplatterDraw {
+    pub index: usize,
+    pub frame: usize,
+    pub size: SplatterSize,
+    pub x: f32,
+    pub y: f32,
+}
+
+struct GpuSplatterTexture {
+    // One view per source frame, each a single-layer slice of the same
+    // texture array so the fragment shader keeps sampling a plain
+    // `texture_2d` while `draw.frame` selects which layer's view is bound.
+    frame_views: Vec<wgpu::TextureView>,
+    width: u32,
+    height: u32,
+}
+
+impl GpuSplatterTexture {
+    // Mirrors `SplatterSet::frame`'s clamp-to-last-frame behavior.
+    fn view_for_frame(&self, frame: usize) -> &wgpu::TextureView {
+        self.frame_views
+            .get(frame)
+            .unwrap_or_else(|| self.frame_views.last().expect("GpuSplatterTexture has no frames"))
+    }
+}
+
+// Uploads the eight `SplatterImages` sets as RGBA textures once (driven off
+// `precompute()`) and composites batches of `SplatterDraw`s in a single
+// textured-quad render pass, blending the straight-alpha textures with
+// `SrcAlpha`/`OneMinusSrcAlpha` into a premultiplied framebuffer.
+pub struct GpuSplatterRenderer {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    pipeline: wgpu::RenderPipeline,
+    bind_group_layout: wgpu::BindGroupLayout,
+    sampler: wgpu::Sampler,
+    textures: [[GpuSplatterTexture; 2]; 4],
+}
+
+const QUAD_SHADER: &str = r#"
+struct VertexInput {
+    @location(0) position: vec2<f32>,
+    @location(1) uv: vec2<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+};
+
+@vertex
+fn vs_main(in: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+    out.clip_position = vec4<f32>(in.position, 0.0, 1.0);
+    out.uv = in.uv;
+    return out;
+}
+
+@group(0) @binding(0) var splatter_texture: texture_2d<f32>;
+@group(0) @binding(1) var splatter_sampler: sampler;
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    return textureSample(splatter_texture, splatter_sampler, in.uv);
+}
+"#;
+
+impl GpuSplatterTexture {
+    // Uploads every frame of a splatter/size pair into one texture array (one
+    // array layer per frame) so `draw.frame` can select a layer instead of
+    // every draw rendering frame 0.
+    fn upload(device: &wgpu::Device, queue: &wgpu::Queue, frames: &[&DynamicImage]) -> GpuSplatterTexture {
+        let layer_count = frames.len() as u32;
+        let (width, height) = frames[0].to_rgba8().dimensions();
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("splatter-texture"),
+            size: wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: layer_count,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8UnormSrgb,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+        for (layer, img) in frames.iter().enumerate() {
+            let rgba = img.to_rgba8();
+            queue.write_texture(
+                wgpu::ImageCopyTexture {
+                    texture: &texture,
+                    mip_level: 0,
+                    origin: wgpu::Origin3d {
+                        x: 0,
+                        y: 0,
+                        z: layer as u32,
+                    },
+                    aspect: wgpu::TextureAspect::All,
+                },
+                &rgba,
+                wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(4 * width),
+                    rows_per_image: Some(height),
+                },
+                wgpu::Extent3d {
+                    width,
+                    height,
+                    depth_or_array_layers: 1,
+                },
+            );
+        }
+        let frame_views = (0..layer_count)
+            .map(|layer| {
+                texture.create_view(&wgpu::TextureViewDescriptor {
+                    label: Some("splatter-texture-frame-view"),
+                    dimension: Some(wgpu::TextureViewDimension::D2),
+                    base_array_layer: layer,
+                    array_layer_count: Some(1),
+                    ..Default::default()
+                })
+            })
+            .collect();
+        GpuSplatterTexture {
+            frame_views,
+            width,
+            height,
+        }
+    }
+}
+
+impl GpuSplatterRenderer {
+    pub fn new(device: wgpu::Device, queue: wgpu::Queue) -> GpuSplatterRenderer {
+        precompute();
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("splatter-bind-group-layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        });
+
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("splatter-sampler"),
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("splatter-quad-shader"),
+            source: wgpu::ShaderSource::Wgsl(QUAD_SHADER.into()),
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("splatter-pipeline-layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("splatter-quad-pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_main",
+                buffers: &[wgpu::VertexBufferLayout {
+                    array_stride: 4 * 4,
+                    step_mode: wgpu::VertexStepMode::Vertex,
+                    attributes: &wgpu::vertex_attr_array![0 => Float32x2, 1 => Float32x2],
+                }],
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_main",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: wgpu::TextureFormat::Rgba8UnormSrgb,
+                    // Splatter assets are uploaded straight-alpha (via
+                    // `to_rgba8()`, same as the CPU `imageops::overlay` path),
+                    // so blend with the standard straight-alpha "over" factors
+                    // rather than premultiplied ones.
+                    blend: Some(wgpu::BlendState {
+                        color: wgpu::BlendComponent {
+                            src_factor: wgpu::BlendFactor::SrcAlpha,
+                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                            operation: wgpu::BlendOperation::Add,
+                        },
+                        alpha: wgpu::BlendComponent {
+                            src_factor: wgpu::BlendFactor::One,
+                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                            operation: wgpu::BlendOperation::Add,
+                        },
+                    }),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+            }),
+            // Each quad is 4 vertices in strip order (TL, TR, BL, BR).
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleStrip,
+                ..Default::default()
+            },
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+        });
+
+        // Built from `get_frames`, the same lookup `Splatter::num` (and thus
+        // the `REGISTRY`/`for_index` CPU path) uses, so `texture_for(index)`
+        // resolves to the same asset as the CPU fallback for every index
+        // instead of a raw `textures[index]` position that disagrees with
+        // `get_frames`'s index offset.
+        let textures = [
+            [
+                GpuSplatterTexture::upload(&device, &queue, &get_frames(0, SplatterSize::Regular)),
+                GpuSplatterTexture::upload(&device, &queue, &get_frames(0, SplatterSize::Large)),
+            ],
+            [
+                GpuSplatterTexture::upload(&device, &queue, &get_frames(1, SplatterSize::Regular)),
+                GpuSplatterTexture::upload(&device, &queue, &get_frames(1, SplatterSize::Large)),
+            ],
+            [
+                GpuSplatterTexture::upload(&device, &queue, &get_frames(2, SplatterSize::Regular)),
+                GpuSplatterTexture::upload(&device, &queue, &get_frames(2, SplatterSize::Large)),
+            ],
+            [
+                GpuSplatterTexture::upload(&device, &queue, &get_frames(3, SplatterSize::Regular)),
+                GpuSplatterTexture::upload(&device, &queue, &get_frames(3, SplatterSize::Large)),
+            ],
+        ];
+
+        GpuSplatterRenderer {
+            device,
+            queue,
+            pipeline,
+            bind_group_layout,
+            sampler,
+            textures,
+        }
+    }
+
+    // `index` is the `for_index`/`REGISTRY` index: 0..=3 resolve through
+    // `get_frames`, same as `Splatter::num`, and anything else falls back to
+    // index 0. Only the baked-in sets are uploaded, so this matches the asset
+    // `render_batch_cpu` picks for the default registry; a splatter added or
+    // replaced via `register()` is not drawn by the GPU path.
+    fn texture_for(&self, index: usize, size: &SplatterSize) -> &GpuSplatterTexture {
+        let set = match index {
+            1 => &self.textures[1],
+            2 => &self.textures[2],
+            3 => &self.textures[3],
+            _ => &self.textures[0],
+        };
+        match size {
+            SplatterSize::Regular => &set[0],
+            SplatterSize::Large => &set[1],
+        }
+    }
+
+    // Composites `draws` in a single render pass and reads the result back
+    // into a `DynamicImage`. Always uses the GPU; callers with no GPU surface
+    // available (e.g. headless rendering) should use `render_batch_cpu`
+    // instead, which composites the same draw batch on the CPU.
+    pub fn render_batch(&self, draws: &[SplatterDraw], width: u32, height: u32) -> DynamicImage {
+        let output = self.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("splatter-batch-output"),
+            size: wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8UnormSrgb,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let view = output.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("splatter-batch-encoder"),
+            });
+        {
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("splatter-batch-pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            pass.set_pipeline(&self.pipeline);
+
+            for draw in draws {
+                let tex = self.texture_for(draw.index, &draw.size);
+                let view = tex.view_for_frame(draw.frame);
+                let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+                    label: Some("splatter-draw-bind-group"),
+                    layout: &self.bind_group_layout,
+                    entries: &[
+                        wgpu::BindGroupEntry {
+                            binding: 0,
+                            resource: wgpu::BindingResource::TextureView(view),
+                        },
+                        wgpu::BindGroupEntry {
+                            binding: 1,
+                            resource: wgpu::BindingResource::Sampler(&self.sampler),
+                        },
+                    ],
+                });
+                // `at()` gives the top-left placement for the CPU path; here
+                // the same half-size offset centers the quad on (x, y) in
+                // normalized device coordinates instead of pixel space.
+                let half_w = tex.width as f32 / width as f32;
+                let half_h = tex.height as f32 / height as f32;
+                let cx = (draw.x / width as f32) * 2.0 - 1.0;
+                let cy = 1.0 - (draw.y / height as f32) * 2.0;
+                let vertices: [f32; 16] = [
+                    cx - half_w, cy + half_h, 0.0, 0.0,
+                    cx + half_w, cy + half_h, 1.0, 0.0,
+                    cx - half_w, cy - half_h, 0.0, 1.0,
+                    cx + half_w, cy - half_h, 1.0, 1.0,
+                ];
+                let vertex_buffer = self.device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                    label: Some("splatter-quad-vertices"),
+                    contents: bytemuck::cast_slice(&vertices),
+                    usage: wgpu::BufferUsages::VERTEX,
+                });
+                pass.set_bind_group(0, &bind_group, &[]);
+                pass.set_vertex_buffer(0, vertex_buffer.slice(..));
+                pass.draw(0..4, 0..1);
+            }
+        }
+        self.queue.submit(Some(encoder.finish()));
+
+        read_back_rgba(&self.device, &self.queue, &output, width, height)
+    }
+}
+
+fn read_back_rgba(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    texture: &wgpu::Texture,
+    width: u32,
+    height: u32,
+) -> DynamicImage {
+    let unpadded_bytes_per_row = 4 * width;
+    let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+    let padded_bytes_per_row = unpadded_bytes_per_row.div_ceil(align) * align;
+    let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("splatter-readback-buffer"),
+        size: (padded_bytes_per_row * height) as u64,
+        usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+        mapped_at_creation: false,
+    });
+    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+        label: Some("splatter-readback-encoder"),
+    });
+    encoder.copy_texture_to_buffer(
+        texture.as_image_copy(),
+        wgpu::ImageCopyBuffer {
+            buffer: &buffer,
+            layout: wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: Some(padded_bytes_per_row),
+                rows_per_image: Some(height),
+            },
+        },
+        wgpu::Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        },
+    );
+    queue.submit(Some(encoder.finish()));
+
+    let slice = buffer.slice(..);
+    slice.map_async(wgpu::MapMode::Read, |_| {});
+    device.poll(wgpu::Maintain::Wait);
+    let padded = slice.get_mapped_range().to_vec();
+    drop(slice);
+    buffer.unmap();
+
+    // wgpu requires each row to start at a 256-byte-aligned offset; strip
+    // that padding back out so the buffer is tightly packed RGBA again.
+    let mut data = Vec::with_capacity((unpadded_bytes_per_row * height) as usize);
+    for row in 0..height as usize {
+        let start = row * padded_bytes_per_row as usize;
+        let end = start + unpadded_bytes_per_row as usize;
+        data.extend_from_slice(&padded[start..end]);
+    }
+
+    let mut image = RgbaImage::from_raw(width, height, data).expect("splatter batch readback size mismatch");
+    unpremultiply_alpha(&mut image);
+    DynamicImage::ImageRgba8(image)
+}
+
+// The render pass clears to transparent and blends straight-alpha source
+// textures with `SrcAlpha`/`OneMinusSrcAlpha`, which (consistently across
+// however many draws land on a pixel) leaves the framebuffer holding
+// premultiplied color with straight alpha in the alpha channel. Divide color
+// back out by alpha so the readback matches the straight-alpha `DynamicImage`
+// `render_batch_cpu`'s `imageops::overlay` path produces.
+fn unpremultiply_alpha(img: &mut RgbaImage) {
+    for px in img.pixels_mut() {
+        let a = px[3] as u32;
+        if a == 0 {
+            px[0] = 0;
+            px[1] = 0;
+            px[2] = 0;
+        } else if a < 255 {
+            for c in 0..3 {
+                px[c] = (((px[c] as u32 * 255) + a / 2) / a).min(255) as u8;
+            }
+        }
+    }
+}
+
+// CPU fallback for `GpuSplatterRenderer::render_batch`, used for headless
+// rendering where no GPU surface is available. Composites the same draw
+// batch by alpha-blending onto a transparent canvas with `image::imageops`.
+pub fn render_batch_cpu(draws: &[SplatterDraw], width: u32, height: u32) -> DynamicImage {
+    let mut canvas = RgbaImage::new(width, height);
+    for draw in draws {
+        let (img, (x, y)) = for_index(draw.index, draw.frame, splatter_size_copy(&draw.size), draw.x, draw.y);
+        image::imageops::overlay(&mut canvas, img.as_ref(), x, y);
+    }
+    DynamicImage::ImageRgba8(canvas)
+}
+
+fn splatter_size_copy(size: &SplatterSize) -> SplatterSize {
+    match size {
+        SplatterSize::Regular => SplatterSize::Regular,
+        SplatterSize::Large => SplatterSize::Large,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solid_image(width: u32, height: u32, color: Rgba<u8>) -> DynamicImage {
+        let mut img = RgbaImage::new(width, height);
+        for px in img.pixels_mut() {
+            *px = color;
+        }
+        DynamicImage::ImageRgba8(img)
+    }
+
+    fn single_frame_splatter(color: Rgba<u8>) -> Splatter {
+        let set = SplatterSet {
+            frames: vec![Arc::new(solid_image(1, 1, color))],
+            delays: vec![DEFAULT_FRAME_DELAY],
+        };
+        Splatter {
+            regular: set.clone(),
+            large: set,
+        }
+    }
+
+    #[test]
+    fn hsv_roundtrip_preserves_rgb() {
+        for &(r, g, b) in &[(0, 0, 0), (255, 255, 255), (200, 100, 50), (10, 200, 30), (30, 60, 220)] {
+            let (h, s, v) = rgb_to_hsv(r, g, b);
+            let (or, og, ob) = hsv_to_rgb(h, s, v);
+            assert!((or as i16 - r as i16).abs() <= 1, "r: {r} vs {or}");
+            assert!((og as i16 - g as i16).abs() <= 1, "g: {g} vs {og}");
+            assert!((ob as i16 - b as i16).abs() <= 1, "b: {b} vs {ob}");
+        }
+    }
+
+    #[test]
+    fn frame_tinted_reapplies_hue_and_multiplies_alpha() {
+        let splatter = single_frame_splatter(Rgba([200, 100, 50, 255]));
+        let tint = Rgba([0, 0, 255, 128]);
+        let tinted = splatter
+            .frame_tinted(999, 0, &SplatterSize::Regular, tint)
+            .to_rgba8();
+        let px = tinted.get_pixel(0, 0);
+        // Source value (200/255) scaled into pure blue, alpha multiplied by
+        // the tint's alpha (128/255).
+        assert_eq!(*px, Rgba([0, 0, 200, 128]));
+    }
+
+    #[test]
+    fn transform_image_identity_preserves_size_and_interior_pixels() {
+        // Bilinear sampling blends with the out-of-bounds-is-transparent
+        // neighbor at the very edge, so check an interior pixel, not a
+        // border one, for a no-op angle/scale.
+        let source = solid_image(4, 4, Rgba([10, 20, 30, 255]));
+        let out = transform_image(&source, 0.0, 1.0).to_rgba8();
+        assert_eq!(out.dimensions(), (4, 4));
+        assert_eq!(*out.get_pixel(1, 1), Rgba([10, 20, 30, 255]));
+        assert_eq!(*out.get_pixel(2, 2), Rgba([10, 20, 30, 255]));
+    }
+
+    #[test]
+    fn transform_image_swaps_dimensions_on_quarter_turn() {
+        let source = solid_image(4, 2, Rgba([10, 20, 30, 255]));
+        let out = transform_image(&source, std::f32::consts::FRAC_PI_2, 1.0);
+        assert_eq!(out.dimensions(), (2, 4));
+    }
+
+    // A scratch directory for `from_dir` tests, unique per test name so
+    // parallel test threads don't collide; the caller cleans it up.
+    fn temp_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("splatters_test_{name}_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn save_png(path: &Path, color: Rgba<u8>) {
+        solid_image(1, 1, color)
+            .save_with_format(path, ImageFormat::Png)
+            .expect("failed to save test PNG");
+    }
+
+    #[test]
+    fn from_dir_sorts_frames_by_filename() {
+        let dir = temp_dir("sorts");
+        save_png(&dir.join("b.png"), Rgba([0, 255, 0, 255]));
+        save_png(&dir.join("a.png"), Rgba([255, 0, 0, 255]));
+        save_png(&dir.join("c.png"), Rgba([0, 0, 255, 255]));
+
+        let set = SplatterSet::from_dir(&dir).expect("from_dir should succeed");
+        assert_eq!(set.frames.len(), 3);
+        assert_eq!(*set.frames[0].to_rgba8().get_pixel(0, 0), Rgba([255, 0, 0, 255]));
+        assert_eq!(*set.frames[1].to_rgba8().get_pixel(0, 0), Rgba([0, 255, 0, 255]));
+        assert_eq!(*set.frames[2].to_rgba8().get_pixel(0, 0), Rgba([0, 0, 255, 255]));
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn from_dir_rejects_directory_with_no_png_frames() {
+        let dir = temp_dir("empty");
+        let result = SplatterSet::from_dir(&dir);
+        assert!(result.is_err());
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn duration_from_numer_denom_ms_keeps_fractional_precision() {
+        assert_eq!(
+            duration_from_numer_denom_ms(1500, 10),
+            Duration::from_millis(150)
+        );
+        // 333/10 ms = 33.3ms, which truncating to whole ms would round down
+        // to 33ms and drift the animation's playback speed over many frames.
+        assert_eq!(
+            duration_from_numer_denom_ms(333, 10),
+            Duration::from_secs_f64(0.0333)
+        );
+    }
+
+    #[test]
+    fn encode_animation_sizes_canvas_to_largest_frame_and_centers_the_rest() {
+        // A small frame followed by a larger one: the GIF canvas should grow
+        // to fit the larger frame, and the small frame should land centered
+        // on it rather than clipped or pinned to a corner.
+        let small = solid_image(2, 2, Rgba([255, 0, 0, 255]));
+        let large = solid_image(4, 4, Rgba([0, 255, 0, 255]));
+        let set = SplatterSet {
+            frames: vec![Arc::new(small), Arc::new(large)],
+            delays: vec![DEFAULT_FRAME_DELAY, DEFAULT_FRAME_DELAY],
+        };
+        let splatter = Splatter {
+            regular: set.clone(),
+            large: set,
+        };
+        // A high, unused index so this doesn't clobber the default 0..=3
+        // registry slots other tests may rely on.
+        register(100, splatter);
+
+        let bytes = encode_animation(100, SplatterSize::Regular, 10);
+        let decoder = GifDecoder::new(Cursor::new(bytes)).expect("failed to decode test gif");
+        let frames: Vec<_> = decoder
+            .into_frames()
+            .collect_frames()
+            .expect("failed to collect test gif frames");
+
+        assert_eq!(frames.len(), 2);
+        for frame in &frames {
+            assert_eq!(frame.buffer().dimensions(), (4, 4));
+        }
+
+        // The 2x2 frame is centered on the 4x4 canvas, so its color should
+        // sit in the middle and the surrounding canvas should stay empty.
+        let first = frames[0].buffer();
+        assert_eq!(*first.get_pixel(1, 1), Rgba([255, 0, 0, 255]));
+        assert_eq!(*first.get_pixel(0, 0), Rgba([0, 0, 0, 0]));
+    }
+}